@@ -0,0 +1,220 @@
+//! A bounding volume hierarchy over a set of [`XZBBox`] regions, used to answer "which regions'
+//! bounding rects cover this block/area" in O(log n) instead of scanning every region per query.
+//! Results are candidates only: a non-rect region's own shape is not tested.
+
+use super::rectangle::XZBBoxRect;
+use super::xzbbox_enum::XZBBox;
+use crate::coordinate_system::cartesian::XZPoint;
+
+enum Node {
+    Leaf {
+        rect: XZBBoxRect,
+        entries: Vec<(usize, XZBBoxRect)>,
+    },
+    Internal {
+        rect: XZBBoxRect,
+        left: Box<Node>,
+        right: Box<Node>,
+    },
+}
+
+impl Node {
+    fn rect(&self) -> XZBBoxRect {
+        match self {
+            Self::Leaf { rect, .. } => *rect,
+            Self::Internal { rect, .. } => *rect,
+        }
+    }
+}
+
+/// Maximum number of regions kept in a single leaf node before splitting further
+const MAX_LEAF_SIZE: usize = 4;
+
+/// A spatial index over a fixed set of [`XZBBox`] regions, supporting fast point and rect queries.
+pub struct XZBBoxTree {
+    root: Option<Node>,
+}
+
+impl XZBBoxTree {
+    /// Build a tree over the bounding rects of the given regions. The returned indices from
+    /// queries refer back to positions in `regions`.
+    pub fn new(regions: &[XZBBox]) -> Self {
+        let entries: Vec<(usize, XZBBoxRect)> = regions
+            .iter()
+            .enumerate()
+            .map(|(i, r)| (i, r.bounding_rect()))
+            .collect();
+
+        Self {
+            root: build(entries),
+        }
+    }
+
+    /// Return the indices of all regions whose own bounding rect covers `p`. Internal node
+    /// rects are only used to prune the descent; every candidate region is re-tested against
+    /// `p` before being returned.
+    pub fn query_point(&self, p: &XZPoint) -> impl Iterator<Item = usize> {
+        let mut out = Vec::new();
+        if let Some(root) = &self.root {
+            collect_point(root, p, &mut out);
+        }
+        out.into_iter()
+    }
+
+    /// Return the indices of all regions whose own bounding rect overlaps `r`. Internal node
+    /// rects are only used to prune the descent; every candidate region is re-tested against
+    /// `r` before being returned.
+    pub fn query_rect(&self, r: &XZBBoxRect) -> Vec<usize> {
+        let mut out = Vec::new();
+        if let Some(root) = &self.root {
+            collect_rect(root, r, &mut out);
+        }
+        out
+    }
+}
+
+impl From<Vec<XZBBox>> for XZBBoxTree {
+    fn from(regions: Vec<XZBBox>) -> Self {
+        Self::new(&regions)
+    }
+}
+
+fn build(mut entries: Vec<(usize, XZBBoxRect)>) -> Option<Node> {
+    if entries.is_empty() {
+        return None;
+    }
+
+    let rect = union_all(&entries);
+
+    if entries.len() <= MAX_LEAF_SIZE {
+        return Some(Node::Leaf { rect, entries });
+    }
+
+    // Split along the longer axis at the median of centroids, SAH-style
+    let width = rect.max().x - rect.min().x;
+    let depth = rect.max().z - rect.min().z;
+
+    if width >= depth {
+        entries.sort_by_key(|(_, r)| r.min().x + r.max().x);
+    } else {
+        entries.sort_by_key(|(_, r)| r.min().z + r.max().z);
+    }
+
+    let mid = entries.len() / 2;
+    let right_entries = entries.split_off(mid);
+    let left_entries = entries;
+
+    match (build(left_entries), build(right_entries)) {
+        (Some(left), Some(right)) => Some(Node::Internal {
+            rect,
+            left: Box::new(left),
+            right: Box::new(right),
+        }),
+        (Some(node), None) | (None, Some(node)) => Some(node),
+        (None, None) => None,
+    }
+}
+
+fn union_all(entries: &[(usize, XZBBoxRect)]) -> XZBBoxRect {
+    let min_x = entries.iter().map(|(_, r)| r.min().x).min().unwrap();
+    let min_z = entries.iter().map(|(_, r)| r.min().z).min().unwrap();
+    let max_x = entries.iter().map(|(_, r)| r.max().x).max().unwrap();
+    let max_z = entries.iter().map(|(_, r)| r.max().z).max().unwrap();
+
+    XZBBoxRect::new(XZPoint { x: min_x, z: min_z }, XZPoint { x: max_x, z: max_z })
+        .expect("union of existing rects must be a valid rect")
+}
+
+fn rect_contains_point(rect: &XZBBoxRect, p: &XZPoint) -> bool {
+    p.x >= rect.min().x && p.x <= rect.max().x && p.z >= rect.min().z && p.z <= rect.max().z
+}
+
+fn rects_overlap(a: &XZBBoxRect, b: &XZBBoxRect) -> bool {
+    a.min().x <= b.max().x && a.max().x >= b.min().x && a.min().z <= b.max().z && a.max().z >= b.min().z
+}
+
+fn collect_point(node: &Node, p: &XZPoint, out: &mut Vec<usize>) {
+    if !rect_contains_point(&node.rect(), p) {
+        return;
+    }
+
+    match node {
+        Node::Leaf { entries, .. } => out.extend(
+            entries
+                .iter()
+                .filter(|(_, rect)| rect_contains_point(rect, p))
+                .map(|(i, _)| *i),
+        ),
+        Node::Internal { left, right, .. } => {
+            collect_point(left, p, out);
+            collect_point(right, p, out);
+        }
+    }
+}
+
+fn collect_rect(node: &Node, r: &XZBBoxRect, out: &mut Vec<usize>) {
+    if !rects_overlap(&node.rect(), r) {
+        return;
+    }
+
+    match node {
+        Node::Leaf { entries, .. } => out.extend(
+            entries
+                .iter()
+                .filter(|(_, rect)| rects_overlap(rect, r))
+                .map(|(i, _)| *i),
+        ),
+        Node::Internal { left, right, .. } => {
+            collect_rect(left, r, out);
+            collect_rect(right, r, out);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rect_region(min: (i32, i32), max: (i32, i32)) -> XZBBox {
+        XZBBox::Rect(
+            XZBBoxRect::new(XZPoint::new(min.0, min.1), XZPoint::new(max.0, max.1)).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_query_point() {
+        let regions = vec![
+            rect_region((0, 0), (10, 10)),
+            rect_region((20, 20), (30, 30)),
+            rect_region((5, 5), (15, 15)),
+        ];
+        let tree = XZBBoxTree::new(&regions);
+
+        let mut hits: Vec<usize> = tree.query_point(&XZPoint::new(7, 7)).collect();
+        hits.sort();
+        assert_eq!(hits, vec![0, 2]);
+
+        let hits: Vec<usize> = tree.query_point(&XZPoint::new(25, 25)).collect();
+        assert_eq!(hits, vec![1]);
+
+        let hits: Vec<usize> = tree.query_point(&XZPoint::new(100, 100)).collect();
+        assert!(hits.is_empty());
+    }
+
+    #[test]
+    fn test_query_rect() {
+        let regions = vec![rect_region((0, 0), (10, 10)), rect_region((20, 20), (30, 30))];
+        let tree = XZBBoxTree::new(&regions);
+
+        let query = XZBBoxRect::new(XZPoint::new(5, 5), XZPoint::new(25, 25)).unwrap();
+        let mut hits = tree.query_rect(&query);
+        hits.sort();
+        assert_eq!(hits, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_empty_tree() {
+        let tree = XZBBoxTree::new(&[]);
+        assert!(tree.query_point(&XZPoint::new(0, 0)).next().is_none());
+    }
+}
@@ -0,0 +1,143 @@
+use super::rectangle::XZBBoxRect;
+use crate::coordinate_system::cartesian::{XZPoint, XZVector};
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// Bounding box shaped as a disc, useful for "generate everything within N blocks of this point".
+#[derive(Clone, Copy, Debug)]
+pub struct XZBBoxDisc {
+    center: XZPoint,
+    radius: i32,
+}
+
+impl XZBBoxDisc {
+    /// Construct a disc bbox from a center point and a non-negative radius
+    pub fn new(center: XZPoint, radius: i32) -> Result<Self, String> {
+        if radius < 0 {
+            return Err(format!(
+                "Invalid XZBBox::Disc: radius should be >= 0, but encountered {radius}"
+            ));
+        }
+
+        Ok(Self { center, radius })
+    }
+
+    /// Check whether an XZPoint is covered
+    pub fn contains(&self, xzpoint: &XZPoint) -> bool {
+        let dx = xzpoint.x as i64 - self.center.x as i64;
+        let dz = xzpoint.z as i64 - self.center.z as i64;
+        let radius = self.radius as i64;
+
+        dx * dx + dz * dz <= radius * radius
+    }
+
+    /// Return the circumscribed rectangle of the disc
+    pub fn bounding_rect(&self) -> XZBBoxRect {
+        // Widen to i64 like `contains` does, then clamp back to i32 range instead of
+        // overflowing when the center sits near i32::MIN/MAX.
+        let clamp_to_i32 = |v: i64| v.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+
+        let center_x = self.center.x as i64;
+        let center_z = self.center.z as i64;
+        let radius = self.radius as i64;
+
+        XZBBoxRect::new(
+            XZPoint {
+                x: clamp_to_i32(center_x - radius),
+                z: clamp_to_i32(center_z - radius),
+            },
+            XZPoint {
+                x: clamp_to_i32(center_x + radius),
+                z: clamp_to_i32(center_z + radius),
+            },
+        )
+        .expect("center/radius derived bounds must form a valid rect")
+    }
+
+    /// Apply an integer affine matrix `[m0, m1, m2, m3]`, transforming the center and scaling
+    /// the radius by the matrix's uniform scale factor (`sqrt(|det|)`, exact for the 8
+    /// axis-aligned rotation/mirror symmetries and any uniform integer scale built from them)
+    pub fn transform(&self, m: &[i32; 4]) -> XZBBoxDisc {
+        let det = (m[0] * m[3] - m[1] * m[2]).unsigned_abs();
+        let scale = (det as f64).sqrt().round() as i32;
+
+        XZBBoxDisc {
+            center: self.center.transform(m),
+            radius: self.radius * scale,
+        }
+    }
+}
+
+impl fmt::Display for XZBBoxDisc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Disc{{center: {:?}, radius: {}}}", self.center, self.radius)
+    }
+}
+
+impl Add<XZVector> for XZBBoxDisc {
+    type Output = XZBBoxDisc;
+
+    fn add(self, other: XZVector) -> XZBBoxDisc {
+        XZBBoxDisc {
+            center: self.center + other,
+            radius: self.radius,
+        }
+    }
+}
+
+impl AddAssign<XZVector> for XZBBoxDisc {
+    fn add_assign(&mut self, other: XZVector) {
+        self.center = self.center + other;
+    }
+}
+
+impl Sub<XZVector> for XZBBoxDisc {
+    type Output = XZBBoxDisc;
+
+    fn sub(self, other: XZVector) -> XZBBoxDisc {
+        XZBBoxDisc {
+            center: self.center - other,
+            radius: self.radius,
+        }
+    }
+}
+
+impl SubAssign<XZVector> for XZBBoxDisc {
+    fn sub_assign(&mut self, other: XZVector) {
+        self.center = self.center - other;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new_rejects_negative_radius() {
+        assert!(XZBBoxDisc::new(XZPoint::new(0, 0), -1).is_err());
+    }
+
+    #[test]
+    fn test_contains() {
+        let disc = XZBBoxDisc::new(XZPoint::new(0, 0), 5).unwrap();
+        assert!(disc.contains(&XZPoint::new(0, 0)));
+        assert!(disc.contains(&XZPoint::new(3, 4))); // exactly on the boundary
+        assert!(!disc.contains(&XZPoint::new(4, 4)));
+    }
+
+    #[test]
+    fn test_bounding_rect() {
+        let disc = XZBBoxDisc::new(XZPoint::new(10, 10), 5).unwrap();
+        let rect = disc.bounding_rect();
+        assert_eq!(rect.min(), XZPoint::new(5, 5));
+        assert_eq!(rect.max(), XZPoint::new(15, 15));
+    }
+
+    #[test]
+    fn test_bounding_rect_does_not_overflow_near_i32_bounds() {
+        let disc = XZBBoxDisc::new(XZPoint::new(i32::MAX, i32::MIN), 10).unwrap();
+        let rect = disc.bounding_rect();
+        assert_eq!(rect.max(), XZPoint::new(i32::MAX, i32::MIN + 10));
+        assert_eq!(rect.min(), XZPoint::new(i32::MAX - 10, i32::MIN));
+    }
+}
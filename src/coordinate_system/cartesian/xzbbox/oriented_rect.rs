@@ -0,0 +1,239 @@
+use super::rectangle::XZBBoxRect;
+use crate::coordinate_system::cartesian::{XZPoint, XZVector};
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// Snap a value to the nearest integer when it's within floating-point error of one, so
+/// near-axis rotations (e.g. 90 degrees, where `cos` comes out as `6.12e-17` rather than `0`)
+/// don't inflate the bounding rect by a block via a stray `floor`/`ceil`.
+fn snap_near_integer(v: f64) -> f64 {
+    let rounded = v.round();
+    if (v - rounded).abs() < 1e-9 {
+        rounded
+    } else {
+        v
+    }
+}
+
+/// Bounding box shaped as a rectangle rotated around its center, so a region can be aligned
+/// with a runway, coastline, or street grid that isn't north-south aligned.
+#[derive(Clone, Copy, Debug)]
+pub struct XZBBoxOrientedRect {
+    center: XZPoint,
+    half_x: i32,
+    half_z: i32,
+    rotation_rad: f64,
+}
+
+impl XZBBoxOrientedRect {
+    /// Construct an oriented rect bbox from a center, its axis-aligned half extents (before
+    /// rotation), and a rotation in radians
+    pub fn new(center: XZPoint, half_x: i32, half_z: i32, rotation_rad: f64) -> Result<Self, String> {
+        if half_x < 0 {
+            return Err(format!(
+                "Invalid XZBBox::OrientedRect: half_x should be >= 0, but encountered {half_x}"
+            ));
+        }
+
+        if half_z < 0 {
+            return Err(format!(
+                "Invalid XZBBox::OrientedRect: half_z should be >= 0, but encountered {half_z}"
+            ));
+        }
+
+        Ok(Self {
+            center,
+            half_x,
+            half_z,
+            rotation_rad,
+        })
+    }
+
+    /// Check whether an XZPoint is covered, by transforming it into the rect's local frame
+    pub fn contains(&self, xzpoint: &XZPoint) -> bool {
+        let dx = (xzpoint.x - self.center.x) as f64;
+        let dz = (xzpoint.z - self.center.z) as f64;
+
+        let cos = self.rotation_rad.cos();
+        let sin = self.rotation_rad.sin();
+
+        let local_x = dx * cos + dz * sin;
+        let local_z = -dx * sin + dz * cos;
+
+        local_x.abs() <= self.half_x as f64 && local_z.abs() <= self.half_z as f64
+    }
+
+    /// Return the axis-aligned rectangle enclosing the four rotated corners
+    pub fn bounding_rect(&self) -> XZBBoxRect {
+        let cos = self.rotation_rad.cos();
+        let sin = self.rotation_rad.sin();
+        let half_x = self.half_x as f64;
+        let half_z = self.half_z as f64;
+
+        let corners = [(-half_x, -half_z), (half_x, -half_z), (half_x, half_z), (-half_x, half_z)];
+
+        let mut min_x = f64::MAX;
+        let mut max_x = f64::MIN;
+        let mut min_z = f64::MAX;
+        let mut max_z = f64::MIN;
+
+        for (lx, lz) in corners {
+            // local -> world: inverse of the rotation applied in `contains`
+            let wx = snap_near_integer(self.center.x as f64 + lx * cos - lz * sin);
+            let wz = snap_near_integer(self.center.z as f64 + lx * sin + lz * cos);
+
+            min_x = min_x.min(wx);
+            max_x = max_x.max(wx);
+            min_z = min_z.min(wz);
+            max_z = max_z.max(wz);
+        }
+
+        XZBBoxRect::new(
+            XZPoint {
+                x: min_x.floor() as i32,
+                z: min_z.floor() as i32,
+            },
+            XZPoint {
+                x: max_x.ceil() as i32,
+                z: max_z.ceil() as i32,
+            },
+        )
+        .expect("rotated corner bounds must form a valid rect")
+    }
+
+    /// Apply an integer affine matrix `[m0, m1, m2, m3]`, transforming the center and folding
+    /// the matrix's rotation/reflection and scale into `rotation_rad`/`half_x`/`half_z`.
+    ///
+    /// `half_x` and `half_z` are scaled independently by how far the matrix stretches the rect's
+    /// local x-axis and local z-axis, rather than a single shared scale, so a scale along the
+    /// rect's own axes is handled exactly; a stretch that is not along them turns the rect into
+    /// a parallelogram, which the result only approximates. A mirror matrix (negative
+    /// determinant) needs no special case: since the rect is symmetric about its center along
+    /// both local axes, the point set it covers is identical whether the new orientation is
+    /// reached by a rotation or a reflection, as long as the local x-axis direction (and thus
+    /// `rotation_rad`) and the two half-extents come out right.
+    pub fn transform(&self, m: &[i32; 4]) -> XZBBoxOrientedRect {
+        let cos = self.rotation_rad.cos();
+        let sin = self.rotation_rad.sin();
+        let (m0, m1, m2, m3) = (m[0] as f64, m[1] as f64, m[2] as f64, m[3] as f64);
+
+        // Images of the local x-axis (cos, sin) and local z-axis (-sin, cos) under the matrix
+        let axis_x = (m0 * cos + m1 * sin, m2 * cos + m3 * sin);
+        let axis_z = (-m0 * sin + m1 * cos, -m2 * sin + m3 * cos);
+
+        XZBBoxOrientedRect {
+            center: self.center.transform(m),
+            half_x: (self.half_x as f64 * axis_x.0.hypot(axis_x.1)).round() as i32,
+            half_z: (self.half_z as f64 * axis_z.0.hypot(axis_z.1)).round() as i32,
+            rotation_rad: axis_x.1.atan2(axis_x.0),
+        }
+    }
+}
+
+impl fmt::Display for XZBBoxOrientedRect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "OrientedRect{{center: {:?}, half_x: {}, half_z: {}, rotation_rad: {}}}",
+            self.center, self.half_x, self.half_z, self.rotation_rad
+        )
+    }
+}
+
+impl Add<XZVector> for XZBBoxOrientedRect {
+    type Output = XZBBoxOrientedRect;
+
+    fn add(self, other: XZVector) -> XZBBoxOrientedRect {
+        XZBBoxOrientedRect {
+            center: self.center + other,
+            ..self
+        }
+    }
+}
+
+impl AddAssign<XZVector> for XZBBoxOrientedRect {
+    fn add_assign(&mut self, other: XZVector) {
+        self.center = self.center + other;
+    }
+}
+
+impl Sub<XZVector> for XZBBoxOrientedRect {
+    type Output = XZBBoxOrientedRect;
+
+    fn sub(self, other: XZVector) -> XZBBoxOrientedRect {
+        XZBBoxOrientedRect {
+            center: self.center - other,
+            ..self
+        }
+    }
+}
+
+impl SubAssign<XZVector> for XZBBoxOrientedRect {
+    fn sub_assign(&mut self, other: XZVector) {
+        self.center = self.center - other;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new_rejects_negative_half_extents() {
+        assert!(XZBBoxOrientedRect::new(XZPoint::new(0, 0), -1, 5, 0.0).is_err());
+        assert!(XZBBoxOrientedRect::new(XZPoint::new(0, 0), 5, -1, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_contains_axis_aligned() {
+        let rect = XZBBoxOrientedRect::new(XZPoint::new(0, 0), 5, 2, 0.0).unwrap();
+        assert!(rect.contains(&XZPoint::new(5, 2)));
+        assert!(!rect.contains(&XZPoint::new(6, 0)));
+    }
+
+    #[test]
+    fn test_contains_45_degrees() {
+        // A 10x10 square (half extents 5) rotated 45 degrees: its unrotated corner (5,5)
+        // sits about 7.07 out along the local x-axis, beyond half_x, so it's excluded.
+        let rect = XZBBoxOrientedRect::new(XZPoint::new(0, 0), 5, 5, std::f64::consts::FRAC_PI_4).unwrap();
+        assert!(rect.contains(&XZPoint::new(0, 0)));
+        assert!(!rect.contains(&XZPoint::new(5, 5)));
+    }
+
+    #[test]
+    fn test_bounding_rect_90_degrees() {
+        // Rotating a 10x4 (half_x=5, half_z=2) rect by 90 degrees swaps its footprint.
+        // cos(FRAC_PI_2) isn't exactly 0 in f64, so this also exercises `snap_near_integer`
+        // not inflating the AABB by a stray block.
+        let rect = XZBBoxOrientedRect::new(XZPoint::new(0, 0), 5, 2, std::f64::consts::FRAC_PI_2).unwrap();
+        let bounds = rect.bounding_rect();
+        assert_eq!(bounds.min(), XZPoint::new(-2, -5));
+        assert_eq!(bounds.max(), XZPoint::new(2, 5));
+    }
+
+    #[test]
+    fn test_transform_mirror_matches_reflected_containment() {
+        // Mirror across the z-axis (negate x). The rect is symmetric about its center, so the
+        // transformed rect must still contain the point that a true reflection would produce.
+        let rect = XZBBoxOrientedRect::new(XZPoint::new(0, 0), 5, 2, 0.0).unwrap();
+        let mirrored = rect.transform(&[-1, 0, 0, 1]);
+
+        assert_eq!(mirrored.half_x, 5);
+        assert_eq!(mirrored.half_z, 2);
+        // A point just inside the original rect, reflected across the z-axis, must still be
+        // covered by the mirrored rect.
+        for p in [XZPoint::new(4, 1), XZPoint::new(-3, -1), XZPoint::new(0, 0)] {
+            assert!(rect.contains(&p));
+            assert!(mirrored.contains(&XZPoint::new(-p.x, p.z)));
+        }
+    }
+
+    #[test]
+    fn test_transform_anisotropic_scale() {
+        let rect = XZBBoxOrientedRect::new(XZPoint::new(0, 0), 5, 2, 0.0).unwrap();
+        let scaled = rect.transform(&[2, 0, 0, 3]);
+
+        assert_eq!(scaled.half_x, 10);
+        assert_eq!(scaled.half_z, 6);
+    }
+}
@@ -0,0 +1,154 @@
+use super::rectangle::XZBBoxRect;
+use crate::coordinate_system::cartesian::{XZPoint, XZVector};
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// Bounding box shaped as an arbitrary simple polygon, given as an ordered list of vertices.
+#[derive(Clone, Debug)]
+pub struct XZBBoxPolygon {
+    vertices: Vec<XZPoint>,
+}
+
+impl XZBBoxPolygon {
+    /// Construct a polygon bbox from an ordered list of vertices
+    pub fn new(vertices: Vec<XZPoint>) -> Result<Self, String> {
+        if vertices.len() < 3 {
+            return Err(format!(
+                "Invalid XZBBox::Polygon: at least 3 vertices are required, but got {}",
+                vertices.len()
+            ));
+        }
+
+        Ok(Self { vertices })
+    }
+
+    /// Check whether an XZPoint is covered, using an integer even-odd ray-casting test
+    pub fn contains(&self, xzpoint: &XZPoint) -> bool {
+        let mut inside = false;
+        let n = self.vertices.len();
+
+        for i in 0..n {
+            let a = self.vertices[i];
+            let b = self.vertices[(i + 1) % n];
+
+            let z_in_range = if a.z < b.z {
+                xzpoint.z >= a.z && xzpoint.z < b.z
+            } else {
+                xzpoint.z >= b.z && xzpoint.z < a.z
+            };
+
+            if !z_in_range {
+                continue;
+            }
+
+            // Sign of (b-a) x (p-a); crosses the edge when p.x is left of it
+            let cross = (b.x - a.x) as i64 * (xzpoint.z - a.z) as i64
+                - (b.z - a.z) as i64 * (xzpoint.x - a.x) as i64;
+
+            let crosses = if b.z > a.z { cross > 0 } else { cross < 0 };
+
+            if crosses {
+                inside = !inside;
+            }
+        }
+
+        inside
+    }
+
+    /// Return the circumscribed rectangle of the polygon
+    pub fn bounding_rect(&self) -> XZBBoxRect {
+        let min_x = self.vertices.iter().map(|p| p.x).min().unwrap();
+        let max_x = self.vertices.iter().map(|p| p.x).max().unwrap();
+        let min_z = self.vertices.iter().map(|p| p.z).min().unwrap();
+        let max_z = self.vertices.iter().map(|p| p.z).max().unwrap();
+
+        XZBBoxRect::new(XZPoint { x: min_x, z: min_z }, XZPoint { x: max_x, z: max_z })
+            .expect("min/max derived from existing vertices must form a valid rect")
+    }
+
+    /// Apply an integer affine matrix `[m0, m1, m2, m3]` to every vertex
+    pub fn transform(&self, m: &[i32; 4]) -> XZBBoxPolygon {
+        XZBBoxPolygon {
+            vertices: self.vertices.iter().map(|p| p.transform(m)).collect(),
+        }
+    }
+}
+
+impl fmt::Display for XZBBoxPolygon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Polygon{{vertices: {:?}}}", self.vertices)
+    }
+}
+
+impl Add<XZVector> for XZBBoxPolygon {
+    type Output = XZBBoxPolygon;
+
+    fn add(self, other: XZVector) -> XZBBoxPolygon {
+        XZBBoxPolygon {
+            vertices: self.vertices.into_iter().map(|p| p + other).collect(),
+        }
+    }
+}
+
+impl AddAssign<XZVector> for XZBBoxPolygon {
+    fn add_assign(&mut self, other: XZVector) {
+        for p in self.vertices.iter_mut() {
+            *p = *p + other;
+        }
+    }
+}
+
+impl Sub<XZVector> for XZBBoxPolygon {
+    type Output = XZBBoxPolygon;
+
+    fn sub(self, other: XZVector) -> XZBBoxPolygon {
+        XZBBoxPolygon {
+            vertices: self.vertices.into_iter().map(|p| p - other).collect(),
+        }
+    }
+}
+
+impl SubAssign<XZVector> for XZBBoxPolygon {
+    fn sub_assign(&mut self, other: XZVector) {
+        for p in self.vertices.iter_mut() {
+            *p = *p - other;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn square() -> XZBBoxPolygon {
+        XZBBoxPolygon::new(vec![
+            XZPoint::new(0, 0),
+            XZPoint::new(10, 0),
+            XZPoint::new(10, 10),
+            XZPoint::new(0, 10),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_new_rejects_too_few_vertices() {
+        assert!(XZBBoxPolygon::new(vec![XZPoint::new(0, 0), XZPoint::new(1, 1)]).is_err());
+    }
+
+    #[test]
+    fn test_contains_square() {
+        let poly = square();
+        assert!(poly.contains(&XZPoint::new(5, 5)));
+        assert!(poly.contains(&XZPoint::new(0, 0)));
+        assert!(!poly.contains(&XZPoint::new(11, 5)));
+        assert!(!poly.contains(&XZPoint::new(5, -1)));
+    }
+
+    #[test]
+    fn test_bounding_rect() {
+        let poly = square();
+        let rect = poly.bounding_rect();
+        assert_eq!(rect.min(), XZPoint::new(0, 0));
+        assert_eq!(rect.max(), XZPoint::new(10, 10));
+    }
+}
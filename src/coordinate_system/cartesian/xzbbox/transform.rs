@@ -0,0 +1,69 @@
+use super::rectangle::XZBBoxRect;
+use crate::coordinate_system::cartesian::XZPoint;
+
+impl XZPoint {
+    /// Apply an integer affine matrix `[m0, m1, m2, m3]`, computing `(m0*x + m1*z, m2*x +
+    /// m3*z)`. Lets callers apply one of the 8 axis-aligned rotation/mirror symmetries, or an
+    /// integer scale, to a point without leaving integer space.
+    pub fn transform(&self, m: &[i32; 4]) -> XZPoint {
+        XZPoint {
+            x: m[0] * self.x + m[1] * self.z,
+            z: m[2] * self.x + m[3] * self.z,
+        }
+    }
+}
+
+impl XZBBoxRect {
+    /// Apply an integer affine matrix `[m0, m1, m2, m3]` to both corners and re-normalize
+    /// min/max, since a rotation or mirror can swap which corner ends up the min and which
+    /// ends up the max
+    pub fn transform(&self, m: &[i32; 4]) -> XZBBoxRect {
+        let a = self.min().transform(m);
+        let b = self.max().transform(m);
+
+        XZBBoxRect::new(
+            XZPoint {
+                x: a.x.min(b.x),
+                z: a.z.min(b.z),
+            },
+            XZPoint {
+                x: a.x.max(b.x),
+                z: a.z.max(b.z),
+            },
+        )
+        .expect("transformed corners must form a valid rect")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_point_transform_identity() {
+        let p = XZPoint::new(3, 4);
+        assert_eq!(p.transform(&[1, 0, 0, 1]), p);
+    }
+
+    #[test]
+    fn test_point_transform_rotate_90() {
+        // (x, z) -> (-z, x)
+        let p = XZPoint::new(3, 4);
+        assert_eq!(p.transform(&[0, -1, 1, 0]), XZPoint::new(-4, 3));
+    }
+
+    #[test]
+    fn test_point_transform_scale() {
+        let p = XZPoint::new(3, 4);
+        assert_eq!(p.transform(&[2, 0, 0, 2]), XZPoint::new(6, 8));
+    }
+
+    #[test]
+    fn test_rect_transform_rotate_90() {
+        let rect = XZBBoxRect::new(XZPoint::new(0, 0), XZPoint::new(4, 2)).unwrap();
+        let rotated = rect.transform(&[0, -1, 1, 0]);
+
+        assert_eq!(rotated.min(), XZPoint::new(-2, 0));
+        assert_eq!(rotated.max(), XZPoint::new(0, 4));
+    }
+}
@@ -1,3 +1,6 @@
+use super::disc::XZBBoxDisc;
+use super::oriented_rect::XZBBoxOrientedRect;
+use super::polygon::XZBBoxPolygon;
 use super::rectangle::XZBBoxRect;
 use crate::coordinate_system::cartesian::{XZPoint, XZVector};
 use std::fmt;
@@ -7,6 +10,9 @@ use std::ops::{Add, AddAssign, Sub, SubAssign};
 #[derive(Clone, Debug)]
 pub enum XZBBox {
     Rect(XZBBoxRect),
+    Polygon(XZBBoxPolygon),
+    Disc(XZBBoxDisc),
+    OrientedRect(XZBBoxOrientedRect),
 }
 
 impl XZBBox {
@@ -50,10 +56,38 @@ impl XZBBox {
         )?))
     }
 
+    /// Construct polygon shape bbox from an ordered list of vertices
+    pub fn polygon_from_vertices(vertices: Vec<XZPoint>) -> Result<Self, String> {
+        Ok(Self::Polygon(XZBBoxPolygon::new(vertices)?))
+    }
+
+    /// Construct disc shape bbox from a center point and a non-negative radius
+    pub fn disc_from_center_radius(center: XZPoint, radius: i32) -> Result<Self, String> {
+        Ok(Self::Disc(XZBBoxDisc::new(center, radius)?))
+    }
+
+    /// Construct oriented rect shape bbox from a center, half extents and a rotation in radians
+    pub fn oriented_rect_from_center_half_extents(
+        center: XZPoint,
+        half_x: i32,
+        half_z: i32,
+        rotation_rad: f64,
+    ) -> Result<Self, String> {
+        Ok(Self::OrientedRect(XZBBoxOrientedRect::new(
+            center,
+            half_x,
+            half_z,
+            rotation_rad,
+        )?))
+    }
+
     /// Check whether an XZPoint is covered
     pub fn contains(&self, xzpoint: &XZPoint) -> bool {
         match self {
             Self::Rect(r) => r.contains(xzpoint),
+            Self::Polygon(p) => p.contains(xzpoint),
+            Self::Disc(d) => d.contains(xzpoint),
+            Self::OrientedRect(o) => o.contains(xzpoint),
         }
     }
 
@@ -61,7 +95,67 @@ impl XZBBox {
     pub fn bounding_rect(&self) -> XZBBoxRect {
         match self {
             Self::Rect(r) => *r,
+            Self::Polygon(p) => p.bounding_rect(),
+            Self::Disc(d) => d.bounding_rect(),
+            Self::OrientedRect(o) => o.bounding_rect(),
+        }
+    }
+
+    /// Apply an integer affine matrix `[m0, m1, m2, m3]` (computed as `(m0*x + m1*z, m2*x +
+    /// m3*z)` per point) to this region, e.g. one of the 8 axis-aligned rotation/mirror
+    /// symmetries or an integer scale
+    pub fn transform(&self, m: &[i32; 4]) -> XZBBox {
+        match self {
+            Self::Rect(r) => Self::Rect(r.transform(m)),
+            Self::Polygon(p) => Self::Polygon(p.transform(m)),
+            Self::Disc(d) => Self::Disc(d.transform(m)),
+            Self::OrientedRect(o) => Self::OrientedRect(o.transform(m)),
+        }
+    }
+
+    /// Return the circumscribed rectangle covering both `self` and `other`
+    pub fn union_rect(&self, other: &XZBBox) -> XZBBox {
+        let a = self.bounding_rect();
+        let b = other.bounding_rect();
+
+        let min_x = a.min().x.min(b.min().x);
+        let min_z = a.min().z.min(b.min().z);
+        let max_x = a.max().x.max(b.max().x);
+        let max_z = a.max().z.max(b.max().z);
+
+        Self::Rect(
+            XZBBoxRect::new(XZPoint { x: min_x, z: min_z }, XZPoint { x: max_x, z: max_z })
+                .expect("union of two valid rects must be a valid rect"),
+        )
+    }
+
+    /// Return the clamped intersection of the circumscribed rectangles of `self` and `other`,
+    /// or `None` if they don't overlap
+    pub fn intersection(&self, other: &XZBBox) -> Option<XZBBox> {
+        let a = self.bounding_rect();
+        let b = other.bounding_rect();
+
+        let min_x = a.min().x.max(b.min().x);
+        let min_z = a.min().z.max(b.min().z);
+        let max_x = a.max().x.min(b.max().x);
+        let max_z = a.max().z.min(b.max().z);
+
+        if min_x > max_x || min_z > max_z {
+            return None;
         }
+
+        Some(Self::Rect(
+            XZBBoxRect::new(XZPoint { x: min_x, z: min_z }, XZPoint { x: max_x, z: max_z })
+                .expect("clamped min/max must be a valid rect"),
+        ))
+    }
+
+    /// Check whether `other`'s circumscribed rectangle fully sits inside `self`'s
+    pub fn contains_bbox(&self, other: &XZBBox) -> bool {
+        let a = self.bounding_rect();
+        let b = other.bounding_rect();
+
+        a.min().x <= b.min().x && a.min().z <= b.min().z && a.max().x >= b.max().x && a.max().z >= b.max().z
     }
 
     /// Return the min x in all covered blocks
@@ -90,9 +184,13 @@ impl XZBBox {
         let max_x = self.max_x();
         let min_z = self.min_z();
         let max_z = self.max_z();
-        
+
         (min_x..=max_x).flat_map(move |x| {
-            (min_z..=max_z).map(move |z| XZPoint::new(x, z))
+            let bbox = self.clone();
+            (min_z..=max_z).filter_map(move |z| {
+                let point = XZPoint::new(x, z);
+                bbox.contains(&point).then_some(point)
+            })
         })
     }
 }
@@ -101,6 +199,9 @@ impl fmt::Display for XZBBox {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Rect(r) => write!(f, "XZBBox::{r}"),
+            Self::Polygon(p) => write!(f, "XZBBox::{p}"),
+            Self::Disc(d) => write!(f, "XZBBox::{d}"),
+            Self::OrientedRect(o) => write!(f, "XZBBox::{o}"),
         }
     }
 }
@@ -112,6 +213,9 @@ impl Add<XZVector> for XZBBox {
     fn add(self, other: XZVector) -> XZBBox {
         match self {
             Self::Rect(r) => Self::Rect(r + other),
+            Self::Polygon(p) => Self::Polygon(p + other),
+            Self::Disc(d) => Self::Disc(d + other),
+            Self::OrientedRect(o) => Self::OrientedRect(o + other),
         }
     }
 }
@@ -120,6 +224,9 @@ impl AddAssign<XZVector> for XZBBox {
     fn add_assign(&mut self, other: XZVector) {
         match self {
             Self::Rect(r) => *r += other,
+            Self::Polygon(p) => *p += other,
+            Self::Disc(d) => *d += other,
+            Self::OrientedRect(o) => *o += other,
         }
     }
 }
@@ -130,6 +237,9 @@ impl Sub<XZVector> for XZBBox {
     fn sub(self, other: XZVector) -> XZBBox {
         match self {
             Self::Rect(r) => Self::Rect(r - other),
+            Self::Polygon(p) => Self::Polygon(p - other),
+            Self::Disc(d) => Self::Disc(d - other),
+            Self::OrientedRect(o) => Self::OrientedRect(o - other),
         }
     }
 }
@@ -138,6 +248,9 @@ impl SubAssign<XZVector> for XZBBox {
     fn sub_assign(&mut self, other: XZVector) {
         match self {
             Self::Rect(r) => *r -= other,
+            Self::Polygon(p) => *p -= other,
+            Self::Disc(d) => *d -= other,
+            Self::OrientedRect(o) => *o -= other,
         }
     }
 }
@@ -243,4 +356,135 @@ mod test {
         assert!(points.contains(&XZPoint::new(2, 0)));
         assert!(points.contains(&XZPoint::new(2, 1)));
     }
+
+    #[test]
+    fn test_polygon_from_vertices() {
+        let obj = XZBBox::polygon_from_vertices(vec![
+            XZPoint::new(0, 0),
+            XZPoint::new(4, 0),
+            XZPoint::new(4, 4),
+            XZPoint::new(0, 4),
+        ]);
+        assert!(obj.is_ok());
+        let obj = obj.unwrap();
+
+        assert!(obj.contains(&XZPoint::new(2, 2)));
+        assert!(!obj.contains(&XZPoint::new(5, 5)));
+        assert_eq!(obj.min_x(), 0);
+        assert_eq!(obj.max_x(), 4);
+        assert_eq!(obj.min_z(), 0);
+        assert_eq!(obj.max_z(), 4);
+
+        assert!(XZBBox::polygon_from_vertices(vec![XZPoint::new(0, 0), XZPoint::new(1, 1)]).is_err());
+    }
+
+    #[test]
+    fn test_disc_from_center_radius() {
+        let obj = XZBBox::disc_from_center_radius(XZPoint::new(0, 0), 5);
+        assert!(obj.is_ok());
+        let obj = obj.unwrap();
+
+        assert!(obj.contains(&XZPoint::new(3, 4)));
+        assert!(!obj.contains(&XZPoint::new(4, 4)));
+        assert_eq!(obj.min_x(), -5);
+        assert_eq!(obj.max_x(), 5);
+
+        assert!(XZBBox::disc_from_center_radius(XZPoint::new(0, 0), -1).is_err());
+    }
+
+    #[test]
+    fn test_oriented_rect_from_center_half_extents() {
+        let obj = XZBBox::oriented_rect_from_center_half_extents(
+            XZPoint::new(0, 0),
+            5,
+            2,
+            std::f64::consts::FRAC_PI_2,
+        );
+        assert!(obj.is_ok());
+        let obj = obj.unwrap();
+
+        assert!(obj.contains(&XZPoint::new(2, 0)));
+        assert!(!obj.contains(&XZPoint::new(5, 0)));
+
+        assert!(XZBBox::oriented_rect_from_center_half_extents(XZPoint::new(0, 0), -1, 2, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_transform_rect_rotate_90() {
+        // [0,4] x [0,2] rotated 90 degrees: (x, z) -> (-z, x)
+        let bbox = XZBBox::rect_from_xz_lengths(4.0, 2.0).unwrap();
+        let rotated = bbox.transform(&[0, -1, 1, 0]);
+
+        assert_eq!(rotated.min_x(), -2);
+        assert_eq!(rotated.max_x(), 0);
+        assert_eq!(rotated.min_z(), 0);
+        assert_eq!(rotated.max_z(), 4);
+    }
+
+    #[test]
+    fn test_transform_rect_scale() {
+        let bbox = XZBBox::rect_from_xz_lengths(2.0, 2.0).unwrap();
+        let scaled = bbox.transform(&[2, 0, 0, 2]);
+
+        assert_eq!(scaled.min_x(), 0);
+        assert_eq!(scaled.max_x(), 4);
+        assert_eq!(scaled.min_z(), 0);
+        assert_eq!(scaled.max_z(), 4);
+    }
+
+    #[test]
+    fn test_union_rect() {
+        let a = XZBBox::rect_from_xz_lengths(2.0, 2.0).unwrap(); // [0,2] x [0,2]
+        let b = (XZBBox::rect_from_xz_lengths(2.0, 2.0).unwrap()) + XZVector { x: 3, z: 3 }; // [3,5] x [3,5]
+
+        let u = a.union_rect(&b);
+        assert_eq!(u.min_x(), 0);
+        assert_eq!(u.min_z(), 0);
+        assert_eq!(u.max_x(), 5);
+        assert_eq!(u.max_z(), 5);
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = XZBBox::rect_from_xz_lengths(4.0, 4.0).unwrap(); // [0,4] x [0,4]
+        let b = (XZBBox::rect_from_xz_lengths(4.0, 4.0).unwrap()) + XZVector { x: 2, z: 2 }; // [2,6] x [2,6]
+
+        let i = a.intersection(&b).unwrap();
+        assert_eq!(i.min_x(), 2);
+        assert_eq!(i.min_z(), 2);
+        assert_eq!(i.max_x(), 4);
+        assert_eq!(i.max_z(), 4);
+
+        let far = (XZBBox::rect_from_xz_lengths(1.0, 1.0).unwrap()) + XZVector { x: 100, z: 100 };
+        assert!(a.intersection(&far).is_none());
+    }
+
+    #[test]
+    fn test_contains_bbox() {
+        let outer = XZBBox::rect_from_xz_lengths(10.0, 10.0).unwrap(); // [0,10] x [0,10]
+        let inner = (XZBBox::rect_from_xz_lengths(2.0, 2.0).unwrap()) + XZVector { x: 3, z: 3 }; // [3,5] x [3,5]
+        let overlapping = (XZBBox::rect_from_xz_lengths(2.0, 2.0).unwrap()) + XZVector { x: 9, z: 9 };
+
+        assert!(outer.contains_bbox(&inner));
+        assert!(!outer.contains_bbox(&overlapping));
+        assert!(!inner.contains_bbox(&outer));
+    }
+
+    #[test]
+    fn test_polygon_into_iter_restricted_to_shape() {
+        // A triangle inscribed in its own 4x4 bounding rect: only points strictly below
+        // the diagonal (plus those on the two axis edges) should be yielded, not the full
+        // rectangle.
+        let bbox = XZBBox::polygon_from_vertices(vec![
+            XZPoint::new(0, 0),
+            XZPoint::new(4, 0),
+            XZPoint::new(0, 4),
+        ])
+        .unwrap();
+        let points: Vec<XZPoint> = bbox.into_iter().collect();
+
+        assert!(points.contains(&XZPoint::new(0, 0)));
+        assert!(points.contains(&XZPoint::new(3, 0)));
+        assert!(!points.contains(&XZPoint::new(4, 4)));
+    }
 }